@@ -18,7 +18,7 @@ use move_core_types::{
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
     loaded_data::runtime_types::Type,
-    values::{values_impl::Reference, Struct, Value},
+    values::{values_impl::Reference, Struct, Value, Vector},
 };
 use smallvec::{smallvec, SmallVec};
 use std::collections::VecDeque;
@@ -50,6 +50,33 @@ fn compute_constant_size(layout: &MoveTypeLayout) -> Option<usize> {
                     }
                     Some(total_size)
                 },
+                // An enum/variant value serializes as a ULEB128 variant tag followed by
+                // that variant's fields. The whole enum is only constant size if every
+                // variant (tag included) serializes to the same number of bytes - a
+                // single-variant enum trivially satisfies this and behaves like its one
+                // inner record. `type_to_type_layout` yields the un-annotated runtime
+                // layout for enums (mirroring `Runtime` for plain structs), not the
+                // named-field `WithVariants` form, which only shows up for fully
+                // annotated layouts that this native never sees.
+                MoveStructLayout::RuntimeVariants(variants) => {
+                    let mut combined_size: Option<usize> = None;
+                    for (tag, field_layouts) in variants.iter().enumerate() {
+                        let mut fields_size = 0;
+                        for field_layout in field_layouts {
+                            match compute_constant_size(field_layout) {
+                                Some(size) => fields_size += size,
+                                None => return None,
+                            }
+                        }
+                        let size = uleb128_size(tag as u64) + fields_size;
+                        match combined_size {
+                            None => combined_size = Some(size),
+                            Some(existing) if existing != size => return None,
+                            Some(_) => {},
+                        }
+                    }
+                    combined_size
+                },
                 _ => None, // WithFields, WithTypes, and other variants are not constant size
             }
         },
@@ -111,6 +138,170 @@ fn native_to_bytes(
     Ok(smallvec![Value::vector_u8(serialized_value)])
 }
 
+/***************************************************************************************************
+ * native fun from_bytes
+ *
+ *   gas cost: size_of(val_type) * input_unit_cost +        | get type layout
+ *             size_of(bytes) * input_unit_cost              | deserialize value
+ *
+ *             If the type layout cannot be obtained, the failure_cost is charged and the
+ *             call aborts. If deserialization fails (including trailing bytes left over
+ *             after a partial parse), a partial cost + an additional failure_cost will be
+ *             charged and `None` is returned instead of aborting.
+ *
+ **************************************************************************************************/
+/// Rust implementation of Move's `native public fun from_bytes<T>(bytes: vector<u8>): Option<T>`
+#[inline]
+fn native_from_bytes(
+    context: &mut SafeNativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    // pop bytes and type
+    let bytes = safely_pop_arg!(args, Vec<u8>);
+    let arg_type = ty_args.pop().unwrap();
+
+    // get type layout
+    let layout = match context.type_to_type_layout(&arg_type) {
+        Ok(layout) => layout,
+        Err(_) => {
+            context.charge(BCS_TO_BYTES_FAILURE)?;
+            return Err(SafeNativeError::Abort {
+                abort_code: NFE_BCS_SERIALIZATION_FAILURE,
+            });
+        },
+    };
+
+    context.charge(BCS_TO_BYTES_PER_BYTE_SERIALIZED * NumBytes::new(bytes.len() as u64))?;
+
+    // Deserialize against the layout, then reject a parse that didn't consume every byte -
+    // `simple_deserialize` only checks that the layout could be read, not that the whole
+    // input was used, so a value packed with trailing garbage would otherwise round-trip.
+    // BCS is a canonical, deterministic encoding, so `val` has exactly one valid encoding
+    // under `layout`; comparing its *length* against the input is therefore enough to
+    // detect leftover bytes, without re-serializing `val` to compare the bytes themselves.
+    // For constant-size layouts, vectors and plain structs, `compute_serialized_size` gets
+    // us that length without materializing a second output buffer; other layouts are
+    // re-serialized to measure them.
+    let deserialized = Value::simple_deserialize(&bytes, &layout).and_then(|val| {
+        match compute_serialized_size(&val, &layout) {
+            Ok(size) if size == bytes.len() => Some(val),
+            _ => None,
+        }
+    });
+
+    // Option<T> is represented as Option { vec: vector<T> }
+    let result = match deserialized {
+        Some(val) => Vector::pack(&arg_type, vec![val])?,
+        None => {
+            context.charge(BCS_TO_BYTES_FAILURE)?;
+            Vector::pack(&arg_type, vec![])?
+        },
+    };
+
+    Ok(smallvec![Value::struct_(Struct::pack(vec![result]))])
+}
+
+/// Number of bytes a ULEB128-encoded length prefix occupies for `value`.
+/// BCS uses this encoding for vector lengths and enum variant tags.
+fn uleb128_size(mut value: u64) -> usize {
+    let mut size = 0;
+    loop {
+        size += 1;
+        value >>= 7;
+        if value == 0 {
+            break;
+        }
+    }
+    size
+}
+
+/// Computes the exact BCS-serialized byte length of `val` under `layout`. For
+/// constant-size layouts, vectors, `Runtime` structs and native wrappers it mirrors the
+/// recursive structure of `Value::simple_serialize`, but accumulates a running byte count
+/// instead of bytes; any other layout is serialized with `simple_serialize` and measured.
+fn compute_serialized_size(val: &Value, layout: &MoveTypeLayout) -> SafeNativeResult<usize> {
+    // Fast path: most layouts (primitives, fixed-size structs/enums) have a size that
+    // doesn't depend on the value at all, so we never have to look at `val`.
+    if let Some(size) = compute_constant_size(layout) {
+        return Ok(size);
+    }
+
+    match layout {
+        MoveTypeLayout::Vector(elem_layout) => {
+            let elems = val.copy_value()?.value_as::<Vec<Value>>()?;
+            let mut total = uleb128_size(elems.len() as u64);
+            for elem in &elems {
+                total += compute_serialized_size(elem, elem_layout)?;
+            }
+            Ok(total)
+        },
+        MoveTypeLayout::Struct(MoveStructLayout::Runtime(field_layouts)) => {
+            let field_values = val.copy_value()?.value_as::<Struct>()?.unpack()?;
+            let mut total = 0;
+            for (field_val, field_layout) in field_values.zip(field_layouts.iter()) {
+                total += compute_serialized_size(&field_val, field_layout)?;
+            }
+            Ok(total)
+        },
+        MoveTypeLayout::Native(_, inner) => compute_serialized_size(val, inner),
+        // Anything else (WithFields, WithTypes, variant structs with differently-sized
+        // variants, ...) doesn't have a cheap recursive traversal here, so fall back to
+        // a real serialization and measure it.
+        _ => val.simple_serialize(layout).map(|bytes| bytes.len()).ok_or(
+            SafeNativeError::Abort {
+                abort_code: NFE_BCS_SERIALIZATION_FAILURE,
+            },
+        ),
+    }
+}
+
+/***************************************************************************************************
+ * native fun serialized_size
+ *
+ *   gas cost: size_of(val_type) * input_unit_cost +        | get type layout
+ *             size_of(val) * output_unit_cost              | walk value, no output buffer
+ *                                                            (except for layouts that
+ *                                                            compute_serialized_size
+ *                                                            measures by serializing)
+ *
+ **************************************************************************************************/
+/// Rust implementation of Move's `native public fun serialized_size<MoveValue>(v: &MoveValue): u64`
+#[inline]
+fn native_serialized_size(
+    context: &mut SafeNativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    // pop type and value
+    let ref_to_val = safely_pop_arg!(args, Reference);
+    let arg_type = ty_args.pop().unwrap();
+
+    // get type layout
+    let layout = match context.type_to_type_layout(&arg_type) {
+        Ok(layout) => layout,
+        Err(_) => {
+            context.charge(BCS_TO_BYTES_FAILURE)?;
+            return Err(SafeNativeError::Abort {
+                abort_code: NFE_BCS_SERIALIZATION_FAILURE,
+            });
+        },
+    };
+
+    // walk the value against its layout, counting bytes instead of writing them
+    let val = ref_to_val.read_ref()?;
+    let size = compute_serialized_size(&val, &layout)?;
+    context.charge(BCS_TO_BYTES_PER_BYTE_SERIALIZED * NumBytes::new(size as u64))?;
+
+    Ok(smallvec![Value::u64(size as u64)])
+}
+
 /***************************************************************************************************
  * native fun constant_serialized_size
  *
@@ -171,6 +362,8 @@ pub fn make_all(
 ) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
     let funcs = [
         ("to_bytes", native_to_bytes as RawSafeNative),
+        ("from_bytes", native_from_bytes as RawSafeNative),
+        ("serialized_size", native_serialized_size as RawSafeNative),
         (
             "constant_serialized_size",
             native_constant_serialized_size as RawSafeNative,
@@ -179,3 +372,54 @@ pub fn make_all(
 
     builder.make_named_natives(funcs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runtime_struct(fields: Vec<MoveTypeLayout>) -> MoveTypeLayout {
+        MoveTypeLayout::Struct(MoveStructLayout::Runtime(fields))
+    }
+
+    #[test]
+    fn nested_struct_is_constant_size() {
+        let inner = runtime_struct(vec![MoveTypeLayout::U64, MoveTypeLayout::Bool]);
+        let outer = runtime_struct(vec![MoveTypeLayout::Address, inner]);
+        // address (32) + (u64 (8) + bool (1))
+        assert_eq!(compute_constant_size(&outer), Some(32 + 8 + 1));
+    }
+
+    #[test]
+    fn enum_with_equal_sized_variants_is_constant_size() {
+        let layout = MoveTypeLayout::Struct(MoveStructLayout::RuntimeVariants(vec![
+            vec![MoveTypeLayout::U64],
+            vec![MoveTypeLayout::U32, MoveTypeLayout::U32],
+        ]));
+        // Both variants serialize to tag (1 byte, both indices < 128) + 8 bytes of fields:
+        // variant 0 is u64 (8), variant 1 is u32 + u32 (4 + 4).
+        assert_eq!(compute_constant_size(&layout), Some(9));
+    }
+
+    #[test]
+    fn single_variant_enum_behaves_like_its_inner_record() {
+        let inner_fields = vec![MoveTypeLayout::U64, MoveTypeLayout::Bool];
+        let enum_layout = MoveTypeLayout::Struct(MoveStructLayout::RuntimeVariants(vec![
+            inner_fields.clone(),
+        ]));
+        let record_layout = runtime_struct(inner_fields);
+        // Only the variant tag (1 byte) separates the two.
+        assert_eq!(
+            compute_constant_size(&enum_layout),
+            compute_constant_size(&record_layout).map(|size| size + 1)
+        );
+    }
+
+    #[test]
+    fn enum_with_mixed_sized_variants_is_not_constant_size() {
+        let layout = MoveTypeLayout::Struct(MoveStructLayout::RuntimeVariants(vec![
+            vec![MoveTypeLayout::U64],
+            vec![MoveTypeLayout::U64, MoveTypeLayout::Bool],
+        ]));
+        assert_eq!(compute_constant_size(&layout), None);
+    }
+}
@@ -9,20 +9,38 @@ use endless_types::on_chain_config::{Features, TimedFeaturesBuilder};
 use endless_vm::natives;
 use move_cli::base::test::{run_move_unit_tests, UnitTestResult};
 use move_command_line_common::{env::read_bool_env_var, testing::MOVE_COMPILER_V2};
-use move_package::{CompilerConfig, CompilerVersion};
+use move_compiler::compiled_unit::CompiledUnit;
+use move_coverage::{coverage_map::CoverageMap, summary::summarize_inst_cov};
+use move_package::{BuildConfig, CompilerConfig, CompilerVersion};
 use move_unit_test::UnitTestingConfig;
 use move_vm_runtime::native_functions::NativeFunctionTable;
+use std::path::Path;
 use tempfile::tempdir;
 
+/// Opt-in flag mirroring `MOVE_COMPILER_V2`: when set, the test run records Move source
+/// coverage instead of discarding it, so maintainers can see which framework functions
+/// are missing unit tests.
+const MOVE_COVERAGE: &str = "MOVE_COVERAGE";
+
 fn run_tests_for_pkg(path_to_pkg: impl Into<String>) {
     let pkg_path = path_in_crate(path_to_pkg);
+    let compute_coverage = read_bool_env_var(MOVE_COVERAGE);
     let mut compiler_config = CompilerConfig {
         known_attributes: extended_checks::get_all_attribute_names().clone(),
         ..Default::default()
     };
+    // When collecting coverage, install into the package's own directory (so build
+    // artifacts land under `<pkg_path>/build`, beside the `.coverage_map.mvcov` the
+    // harness writes into the package) instead of a throwaway tempdir whose guard is
+    // dropped, and the directory removed, as soon as its path has been taken.
+    let install_dir = if compute_coverage {
+        pkg_path.clone()
+    } else {
+        tempdir().unwrap().path().to_path_buf()
+    };
     let mut build_config = move_package::BuildConfig {
         test_mode: true,
-        install_dir: Some(tempdir().unwrap().path().to_path_buf()),
+        install_dir: Some(install_dir.clone()),
         compiler_config: compiler_config.clone(),
         full_model_generation: true, // Run extended checks also on test code
         ..Default::default()
@@ -34,7 +52,7 @@ fn run_tests_for_pkg(path_to_pkg: impl Into<String>) {
         UnitTestingConfig::default_with_bound(Some(100_000)),
         endless_test_natives(),
         /* cost_table */ None,
-        /* compute_coverage */ false,
+        compute_coverage,
         &mut std::io::stdout(),
     )
     .unwrap();
@@ -42,16 +60,18 @@ fn run_tests_for_pkg(path_to_pkg: impl Into<String>) {
         panic!("move unit tests failed")
     }
     if read_bool_env_var(MOVE_COMPILER_V2) {
-        // Run test against v2 when MOVE_COMPILER_V2 is set
+        // Run test against v2 when MOVE_COMPILER_V2 is set. Note: both this run and the
+        // v1 run above write coverage to the same `.coverage_map.mvcov`, so when
+        // MOVE_COVERAGE is also set, the summary below only reflects this (v2) run.
         compiler_config.compiler_version = Some(CompilerVersion::V2);
         build_config.compiler_config = compiler_config;
         ok = run_move_unit_tests(
             &pkg_path,
-            build_config,
+            build_config.clone(),
             UnitTestingConfig::default_with_bound(Some(100_000)),
             endless_test_natives(),
             /* cost_table */ None,
-            /* compute_coverage */ false,
+            compute_coverage,
             &mut std::io::stdout(),
         )
         .unwrap();
@@ -59,6 +79,71 @@ fn run_tests_for_pkg(path_to_pkg: impl Into<String>) {
     if ok != UnitTestResult::Success {
         panic!("move unit tests failed for compiler v2")
     }
+    if compute_coverage {
+        print_coverage_summary(&pkg_path, &install_dir, build_config);
+    }
+}
+
+/// Prints, for every module in the package rooted at `pkg_path`, how many of its
+/// bytecode instructions were exercised by the unit tests that just ran.
+///
+/// `build_config` must be the exact config the tests were run under (`test_mode`,
+/// `known_attributes`, and compiler version all included) - summarizing against modules
+/// built with a different config could measure a different instruction layout than what
+/// actually executed.
+fn print_coverage_summary(pkg_path: &Path, install_dir: &Path, build_config: BuildConfig) {
+    // The unit-test harness conventionally writes `.coverage_map.mvcov` next to the
+    // package it tested, not under the install dir's `build/` output - check that
+    // location first and only fall back to `build/` in case that ever changes.
+    let candidate_paths = [
+        pkg_path.join(".coverage_map.mvcov"),
+        install_dir.join(".coverage_map.mvcov"),
+        install_dir.join("build").join("coverage_map.mvcov"),
+    ];
+    let coverage_map_path = candidate_paths
+        .iter()
+        .find(|path| path.exists())
+        .unwrap_or_else(|| {
+            panic!(
+                "MOVE_COVERAGE was requested but no coverage map was found for {} (checked {:?})",
+                pkg_path.display(),
+                candidate_paths
+            )
+        });
+    let coverage_map = CoverageMap::from_binary_file(coverage_map_path)
+        .unwrap_or_else(|err| {
+            panic!(
+                "failed to parse coverage map at {}: {}",
+                coverage_map_path.display(),
+                err
+            )
+        })
+        .to_unified_exec_map();
+
+    let package = build_config
+        .compile_package(pkg_path, &mut std::io::stdout())
+        .expect("recompiling package for coverage summary failed");
+
+    println!("-- coverage summary for {} --", pkg_path.display());
+    for unit in package.root_modules() {
+        let module = match &unit.unit {
+            CompiledUnit::Module(named_module) => &named_module.module,
+            CompiledUnit::Script(_) => continue,
+        };
+        let summary = summarize_inst_cov(module, &coverage_map);
+        let (covered, total) = summary.function_summaries.values().fold(
+            (0u64, 0u64),
+            |(covered, total), fn_summary| {
+                (covered + fn_summary.covered, total + fn_summary.total)
+            },
+        );
+        println!(
+            "{}: {}/{} instructions covered",
+            module.self_id().name(),
+            covered,
+            total
+        );
+    }
 }
 
 pub fn endless_test_natives() -> NativeFunctionTable {